@@ -2,6 +2,7 @@
 #![no_main]
 
 mod servo;
+mod max7219;
 
 
 use servo::*;
@@ -0,0 +1,61 @@
+//! Bundled 5x7 dot-matrix font used by [`super::scroll::Scroller`].
+//!
+//! Each glyph is 5 columns; within a column, bit `y` (0..=6) lights row `y` (0 = top), bit 7 is
+//! unused. Covers space, digits, uppercase letters and a handful of punctuation marks — enough
+//! for a scrolling status/marquee message. Anything else (including lowercase, which callers
+//! should upper-case first) falls back to a blank cell.
+
+/// Number of columns making up one glyph, not counting any letter-spacing column after it.
+pub const GLYPH_WIDTH: usize = 5;
+
+const BLANK: [u8; GLYPH_WIDTH] = [0x00, 0x00, 0x00, 0x00, 0x00];
+
+/// Looks up the 5-column glyph for `ch`, or a blank cell if it isn't in the font.
+pub fn glyph(ch: char) -> [u8; GLYPH_WIDTH] {
+    match ch {
+        ' ' => BLANK,
+        '!' => [0x00, 0x00, 0x5F, 0x00, 0x00],
+        ',' => [0x00, 0x50, 0x30, 0x00, 0x00],
+        '.' => [0x00, 0x60, 0x60, 0x00, 0x00],
+        '-' => [0x08, 0x08, 0x08, 0x08, 0x08],
+        ':' => [0x00, 0x36, 0x36, 0x00, 0x00],
+        '?' => [0x02, 0x01, 0x51, 0x09, 0x06],
+        '0' => [0x3E, 0x51, 0x49, 0x45, 0x3E],
+        '1' => [0x00, 0x42, 0x7F, 0x40, 0x00],
+        '2' => [0x62, 0x51, 0x49, 0x49, 0x46],
+        '3' => [0x22, 0x41, 0x49, 0x49, 0x36],
+        '4' => [0x18, 0x14, 0x12, 0x7F, 0x10],
+        '5' => [0x27, 0x45, 0x45, 0x45, 0x39],
+        '6' => [0x3C, 0x4A, 0x49, 0x49, 0x30],
+        '7' => [0x01, 0x71, 0x09, 0x05, 0x03],
+        '8' => [0x36, 0x49, 0x49, 0x49, 0x36],
+        '9' => [0x06, 0x49, 0x49, 0x29, 0x1E],
+        'A' => [0x7E, 0x11, 0x11, 0x11, 0x7E],
+        'B' => [0x7F, 0x49, 0x49, 0x49, 0x36],
+        'C' => [0x3E, 0x41, 0x41, 0x41, 0x22],
+        'D' => [0x7F, 0x41, 0x41, 0x22, 0x1C],
+        'E' => [0x7F, 0x49, 0x49, 0x49, 0x41],
+        'F' => [0x7F, 0x09, 0x09, 0x09, 0x01],
+        'G' => [0x3E, 0x41, 0x49, 0x49, 0x7A],
+        'H' => [0x7F, 0x08, 0x08, 0x08, 0x7F],
+        'I' => [0x00, 0x41, 0x7F, 0x41, 0x00],
+        'J' => [0x20, 0x40, 0x41, 0x3F, 0x01],
+        'K' => [0x7F, 0x08, 0x14, 0x22, 0x41],
+        'L' => [0x7F, 0x40, 0x40, 0x40, 0x40],
+        'M' => [0x7F, 0x02, 0x0C, 0x02, 0x7F],
+        'N' => [0x7F, 0x04, 0x08, 0x10, 0x7F],
+        'O' => [0x3E, 0x41, 0x41, 0x41, 0x3E],
+        'P' => [0x7F, 0x09, 0x09, 0x09, 0x06],
+        'Q' => [0x3E, 0x41, 0x51, 0x21, 0x5E],
+        'R' => [0x7F, 0x09, 0x19, 0x29, 0x46],
+        'S' => [0x46, 0x49, 0x49, 0x49, 0x31],
+        'T' => [0x01, 0x01, 0x7F, 0x01, 0x01],
+        'U' => [0x3F, 0x40, 0x40, 0x40, 0x3F],
+        'V' => [0x1F, 0x20, 0x40, 0x20, 0x1F],
+        'W' => [0x3F, 0x40, 0x38, 0x40, 0x3F],
+        'X' => [0x63, 0x14, 0x08, 0x14, 0x63],
+        'Y' => [0x07, 0x08, 0x70, 0x08, 0x07],
+        'Z' => [0x61, 0x51, 0x49, 0x45, 0x43],
+        _ => BLANK,
+    }
+}
@@ -0,0 +1,40 @@
+//! `embedded-graphics` integration for [`MatrixBuffer`], so fonts, primitives and bitmaps from
+//! that ecosystem can be rendered straight onto one or more chained MAX7219 panels.
+
+use embedded_graphics_core::draw_target::DrawTarget;
+use embedded_graphics_core::geometry::{OriginDimensions, Size};
+use embedded_graphics_core::pixelcolor::BinaryColor;
+use embedded_graphics_core::Pixel;
+
+use super::{MatrixBuffer, Transport};
+
+impl<T: Transport, const N: usize> OriginDimensions for MatrixBuffer<T, N> {
+    fn size(&self) -> Size {
+        Size::new((N * 8) as u32, 8)
+    }
+}
+
+impl<T: Transport, const N: usize> DrawTarget for MatrixBuffer<T, N> {
+    type Color = BinaryColor;
+    type Error = core::convert::Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(point, color) in pixels {
+            // embedded-graphics primitives can yield coordinates outside the panel (e.g. a
+            // circle clipped by the display bounds); silently drop anything off-canvas.
+            if point.x < 0 || point.y < 0 {
+                continue;
+            }
+
+            let (x, y) = (point.x as usize, point.y as usize);
+            if x < N * 8 && y < 8 {
+                self.set_pixel(x, y, color.is_on());
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,416 @@
+//! Driver for the MAX7219 8x8 LED matrix / 7-segment display driver chip.
+#![allow(dead_code)]
+
+mod font;
+mod graphics;
+mod scroll;
+mod segment;
+
+pub use scroll::Scroller;
+pub use segment::{Digit, DisplayMode, SegmentDisplay};
+
+use arduino_hal::port::Pin;
+use arduino_hal::hal::port::mode;
+use embedded_hal::spi::SpiBus;
+
+// -- Register addresses per digit (rows?)
+const DIG_0: u8 = 0x1;
+const DIG_1: u8 = 0x2;
+const DIG_2: u8 = 0x3;
+const DIG_3: u8 = 0x4;
+const DIG_4: u8 = 0x5;
+const DIG_5: u8 = 0x6;
+const DIG_6: u8 = 0x7;
+const DIG_7: u8 = 0x8;
+const NO_OP: u8 = 0x0;
+const MODE_DECODE: u8 = 0x9;
+const INTENSITY: u8 = 0xA;
+const SCAN_LIMIT: u8 = 0xB;
+const SHUTDOWN: u8 = 0xC;
+const DISPLAY_TEST: u8 = 0xF;
+
+// Intensity modes
+const INTENSITY_MIN: u8 = 0x0;
+const INTENSITY_MAX: u8 = 0xF;
+
+#[derive(Clone, Copy)]
+pub enum ADDRESS {
+    DIG_0,
+    DIG_1,
+    DIG_2,
+    DIG_3,
+    DIG_4,
+    DIG_5,
+    DIG_6,
+    DIG_7,
+    NO_OP,
+    MODE_DECODE,
+    INTENSITY,
+    SCAN_LIMIT,
+    SHUTDOWN,
+    DISPLAY_TEST,
+}
+
+impl ADDRESS {
+    /// Resolves the enum variant to the numeric opcode expected on D11-D8 of the serial frame.
+    fn opcode(&self) -> u8 {
+        match self {
+            ADDRESS::DIG_0 => DIG_0,
+            ADDRESS::DIG_1 => DIG_1,
+            ADDRESS::DIG_2 => DIG_2,
+            ADDRESS::DIG_3 => DIG_3,
+            ADDRESS::DIG_4 => DIG_4,
+            ADDRESS::DIG_5 => DIG_5,
+            ADDRESS::DIG_6 => DIG_6,
+            ADDRESS::DIG_7 => DIG_7,
+            ADDRESS::NO_OP => NO_OP,
+            ADDRESS::MODE_DECODE => MODE_DECODE,
+            ADDRESS::INTENSITY => INTENSITY,
+            ADDRESS::SCAN_LIMIT => SCAN_LIMIT,
+            ADDRESS::SHUTDOWN => SHUTDOWN,
+            ADDRESS::DISPLAY_TEST => DISPLAY_TEST,
+        }
+    }
+}
+
+// Connectivity:
+// * MOSI to DIN
+// * I/O to LOAD(CS)
+// * SCK to CLK
+
+// SPI data
+// * CLK period min = 100 ns
+// * CLK pulse width high min = 50 ns
+
+// Serial data format:
+// D15 - D12 : Not used
+// D11 - D8: Address
+// D7 - D0: MSB to LSB of data.
+// MAx7219 needs to receive the MSB first.
+
+/// Shifts 16-bit address/data frames out to one or more cascaded MAX7219 chips.
+///
+/// Implemented once for the manual bit-bang pins ([`BitBang`]) and once for hardware SPI
+/// ([`Hardware`]) so [`Max7219`] can drive either without duplicating the register logic. A full
+/// cascaded write is `assert_select`, one `shift_frame` per device in the chain, `deassert_select`
+/// — the chip only latches on that final LOAD/CS transition, so every device in a chain must see
+/// its frame before the chain is latched as one.
+pub trait Transport {
+    /// Pulls LOAD/CS low, opening the chain up to receive frames.
+    fn assert_select(&mut self);
+    /// Shifts one 16-bit address/data frame into the chain.
+    fn shift_frame(&mut self, address: u8, data: u8);
+    /// Raises LOAD/CS, latching every frame shifted in since `assert_select`.
+    fn deassert_select(&mut self);
+}
+
+/// Manual bit-bang transport driving DIN/CLK/LOAD directly.
+pub struct BitBang<'a> {
+    din: &'a mut Pin<mode::Output>,
+    clk: &'a mut Pin<mode::Output>,
+    load: &'a mut Pin<mode::Output>,
+}
+
+impl<'a> Transport for BitBang<'a> {
+    fn assert_select(&mut self) {
+        self.clk.set_low();
+        self.load.set_low();
+    }
+
+    /// Clocks the frame out MSB-first. One `delay_us(1)` between edges comfortably clears the
+    /// datasheet's 50 ns CLK high-width / 100 ns CLK period minimums at the AVR's clock speeds.
+    fn shift_frame(&mut self, address: u8, data: u8) {
+        // Combine the address to the first 8 bits and then append the data in the last 8 bits.
+        let serialized = (address as u16) << 8 | (data as u16);
+
+        for bit in (0..16).rev() {
+            if (serialized >> bit) & 0x1 == 1 {
+                self.din.set_high();
+            } else {
+                self.din.set_low();
+            }
+
+            arduino_hal::delay_us(1);
+            self.clk.set_high();
+            arduino_hal::delay_us(1);
+            self.clk.set_low();
+        }
+    }
+
+    fn deassert_select(&mut self) {
+        // Rising edge on LOAD latches every frame shifted in since `assert_select`.
+        self.load.set_high();
+        arduino_hal::delay_us(1);
+        self.load.set_low();
+    }
+}
+
+/// Hardware SPI transport, for when the target's HAL has a SPI peripheral enabled.
+///
+/// Takes an `SpiBus` rather than an `SpiDevice`: we drive `cs` (wired to LOAD) by hand around
+/// every frame in a chained write, holding it low across several `shift_frame` calls so the
+/// whole chain latches on one rising edge. `SpiDevice` gives no such guarantee — each call is
+/// its own complete bus transaction, free to arbitrate/interleave other peripherals' traffic in
+/// between — so it can't safely share a bus with anything else while our chain write is in
+/// flight. `SpiBus` makes no such promise either way, which is exactly what manual CS control
+/// requires: the caller (not the HAL) owns exclusive access to the bus for the duration.
+pub struct Hardware<SPI> {
+    spi: SPI,
+    cs: Pin<mode::Output>,
+}
+
+impl<SPI: SpiBus> Transport for Hardware<SPI> {
+    fn assert_select(&mut self) {
+        self.cs.set_low();
+    }
+
+    fn shift_frame(&mut self, address: u8, data: u8) {
+        let _ = self.spi.write(&[address, data]);
+    }
+
+    fn deassert_select(&mut self) {
+        self.cs.set_high();
+    }
+}
+
+/// MAX7219 driver, generic over the [`Transport`] used to reach the chip and the number `N` of
+/// devices daisy-chained on it (defaults to a single device).
+///
+/// Build one with [`Max7219::from_pins`] to bit-bang over plain GPIO, or [`Max7219::from_spi`]
+/// to run over a hardware SPI peripheral, which clocks far faster than the bit-bang loop.
+pub struct Max7219<T, const N: usize = 1> {
+    transport: T,
+    /// Device targeted by [`Max7219::write_register`], set via [`Max7219::set_device`].
+    device: usize,
+    /// Current intensity, tracked so [`Max7219::fade_to`] knows where to step from next.
+    intensity: u8,
+}
+
+impl<'a, const N: usize> Max7219<BitBang<'a>, N> {
+    pub fn from_pins(
+        din: &'a mut Pin<mode::Output>,
+        clk: &'a mut Pin<mode::Output>,
+        load: &'a mut Pin<mode::Output>,
+    ) -> Self {
+        Max7219 {
+            transport: BitBang { din, clk, load },
+            device: 0,
+            intensity: INTENSITY_MIN,
+        }
+    }
+}
+
+impl<SPI: SpiBus, const N: usize> Max7219<Hardware<SPI>, N> {
+    /// `spi` must not be shared with other logical devices (no other code may issue bus
+    /// transactions on it) for as long as this `Max7219` is alive, since cascaded writes hold
+    /// `cs`/LOAD low across several calls without any HAL-level bus arbitration.
+    pub fn from_spi(spi: SPI, cs: Pin<mode::Output>) -> Self {
+        Max7219 {
+            transport: Hardware { spi, cs },
+            device: 0,
+            intensity: INTENSITY_MIN,
+        }
+    }
+}
+
+impl<T: Transport, const N: usize> Max7219<T, N> {
+    /// Addresses `device` (0-indexed, closest to the controller) for subsequent calls to
+    /// [`Max7219::write_register`].
+    pub fn set_device(&mut self, device: usize) {
+        debug_assert!(device < N, "device index out of range for this chain");
+        self.device = device;
+    }
+
+    /// Writes `data` to the chip register named by `address`, e.g.
+    /// `max7219.write_register(ADDRESS::INTENSITY, 0x7)`, on the device selected by
+    /// [`Max7219::set_device`].
+    pub fn write_register(&mut self, address: ADDRESS, data: u8) {
+        self.write_register_chained(self.device, address, data);
+    }
+
+    /// Writes `data` to `address` on `device`, padding every other device in the chain with a
+    /// `NO_OP` frame so a single LOAD/CS pulse latches the whole chain at once.
+    pub fn write_register_chained(&mut self, device: usize, address: ADDRESS, data: u8) {
+        debug_assert!(device < N, "device index out of range for this chain");
+
+        let opcode = address.opcode();
+        self.transport.assert_select();
+        // DOUT of device 0 (closest to the controller) feeds DIN of device 1, and so on, so the
+        // frame shifted in *first* travels furthest down the chain and lands in the *last*
+        // device. Shift device N-1's frame first and device 0's last so `device` ends up in the
+        // right chip.
+        for i in (0..N).rev() {
+            if i == device {
+                self.transport.shift_frame(opcode, data);
+            } else {
+                self.transport.shift_frame(NO_OP, 0);
+            }
+        }
+        self.transport.deassert_select();
+    }
+
+    /// Sets every cascaded device's brightness, clamped to the chip's `0..=15` range.
+    pub fn set_intensity(&mut self, level: u8) {
+        self.intensity = level.min(INTENSITY_MAX);
+        for device in 0..N {
+            self.write_register_chained(device, ADDRESS::INTENSITY, self.intensity);
+        }
+    }
+
+    /// Enters (`true`) or exits (`false`) low-power shutdown mode on every cascaded device.
+    /// Framebuffer/digit contents live in the chip's own registers and are unaffected, so the
+    /// display picks up exactly where it left off on wake.
+    pub fn shutdown(&mut self, enabled: bool) {
+        let data = if enabled { 0 } else { 1 };
+        for device in 0..N {
+            self.write_register_chained(device, ADDRESS::SHUTDOWN, data);
+        }
+    }
+
+    /// Enables (`true`) or disables (`false`) display-test mode on every cascaded device, which
+    /// forces every LED on regardless of framebuffer/digit content. Handy as a wiring check.
+    pub fn test(&mut self, enabled: bool) {
+        let data = if enabled { 1 } else { 0 };
+        for device in 0..N {
+            self.write_register_chained(device, ADDRESS::DISPLAY_TEST, data);
+        }
+    }
+
+    /// Steps every cascaded device's brightness one level towards `target` (clamped to
+    /// `0..=15`), waiting `step_delay_ms` before returning. Call it repeatedly, e.g. once per
+    /// main-loop iteration, for a smooth ramp without blocking for the whole fade. Returns
+    /// `true` once `target` has been reached.
+    pub fn fade_to(&mut self, target: u8, step_delay_ms: u16) -> bool {
+        let target = target.min(INTENSITY_MAX);
+        if self.intensity == target {
+            return true;
+        }
+
+        let next = if self.intensity < target {
+            self.intensity + 1
+        } else {
+            self.intensity - 1
+        };
+        self.set_intensity(next);
+
+        arduino_hal::delay_ms(step_delay_ms as u32);
+        self.intensity == target
+    }
+}
+
+/// `ADDRESS::DIG_n` in row order, so a row index can be turned into the register that holds it.
+const DIGIT_ADDRESSES: [ADDRESS; 8] = [
+    ADDRESS::DIG_0,
+    ADDRESS::DIG_1,
+    ADDRESS::DIG_2,
+    ADDRESS::DIG_3,
+    ADDRESS::DIG_4,
+    ADDRESS::DIG_5,
+    ADDRESS::DIG_6,
+    ADDRESS::DIG_7,
+];
+
+/// `MODE_DECODE` value selecting plain matrix mode (no Code-B decoding) for every digit.
+const DECODE_NONE: u8 = 0x00;
+
+/// 8x8-per-device pixel framebuffer sitting on top of a [`Max7219`] chain of `N` devices.
+///
+/// Pixels are addressed in one flat `N * 8` wide by 8 tall coordinate space spanning the whole
+/// chain, device 0 occupying columns `0..8`, device 1 columns `8..16`, and so on. Only rows whose
+/// bytes actually changed since the last [`MatrixBuffer::flush`] are re-transmitted, which keeps
+/// SPI traffic down during animations.
+pub struct MatrixBuffer<T, const N: usize> {
+    driver: Max7219<T, N>,
+    rows: [[u8; 8]; N],
+    /// Per-device bitmask of rows changed since the last flush (bit `y` set => row `y` dirty).
+    dirty: [u8; N],
+}
+
+impl<T: Transport, const N: usize> MatrixBuffer<T, N> {
+    pub fn new(driver: Max7219<T, N>) -> Self {
+        MatrixBuffer {
+            driver,
+            rows: [[0; 8]; N],
+            dirty: [0; N],
+        }
+    }
+
+    /// Runs the MAX7219 power-up sequence on every cascaded device: matrix (no-decode) mode, the
+    /// full 8-digit scan limit, `intensity` (0..=15), display test off, and shutdown cleared to
+    /// enable output. Leaves the buffer cleared and flushes it so the panels start blank.
+    pub fn init(&mut self, intensity: u8) {
+        for device in 0..N {
+            self.driver
+                .write_register_chained(device, ADDRESS::MODE_DECODE, DECODE_NONE);
+            self.driver
+                .write_register_chained(device, ADDRESS::SCAN_LIMIT, 7);
+        }
+        self.driver.set_intensity(intensity);
+        self.driver.test(false);
+        self.driver.shutdown(false);
+
+        self.clear();
+        self.flush();
+    }
+
+    /// Sets every cascaded device's brightness, clamped to the chip's `0..=15` range. See
+    /// [`Max7219::set_intensity`].
+    pub fn set_intensity(&mut self, level: u8) {
+        self.driver.set_intensity(level);
+    }
+
+    /// Enters or exits low-power shutdown mode on every cascaded device. The framebuffer is
+    /// unaffected. See [`Max7219::shutdown`].
+    pub fn shutdown(&mut self, enabled: bool) {
+        self.driver.shutdown(enabled);
+    }
+
+    /// Enables or disables display-test mode on every cascaded device. See [`Max7219::test`].
+    pub fn test(&mut self, enabled: bool) {
+        self.driver.test(enabled);
+    }
+
+    /// Steps every cascaded device's brightness one level towards `target`. See
+    /// [`Max7219::fade_to`].
+    pub fn fade_to(&mut self, target: u8, step_delay_ms: u16) -> bool {
+        self.driver.fade_to(target, step_delay_ms)
+    }
+
+    /// Blanks every pixel in the buffer. Call [`MatrixBuffer::flush`] to push it to the panels.
+    pub fn clear(&mut self) {
+        self.rows = [[0; 8]; N];
+        self.dirty = [0xFF; N];
+    }
+
+    /// Sets or clears the pixel at `(x, y)`, `x` spanning `0..N * 8` and `y` spanning `0..8`.
+    pub fn set_pixel(&mut self, x: usize, y: usize, on: bool) {
+        debug_assert!(x < N * 8 && y < 8, "pixel out of bounds");
+
+        let device = x / 8;
+        let col = x % 8;
+        let mask = 1 << (7 - col);
+
+        let old = self.rows[device][y];
+        let new = if on { old | mask } else { old & !mask };
+        if new != old {
+            self.rows[device][y] = new;
+            self.dirty[device] |= 1 << y;
+        }
+    }
+
+    /// Re-transmits only the rows that changed since the last flush.
+    pub fn flush(&mut self) {
+        for device in 0..N {
+            for row in 0..8 {
+                if self.dirty[device] & (1 << row) != 0 {
+                    self.driver.write_register_chained(
+                        device,
+                        DIGIT_ADDRESSES[row],
+                        self.rows[device][row],
+                    );
+                }
+            }
+            self.dirty[device] = 0;
+        }
+    }
+}
@@ -0,0 +1,87 @@
+//! Horizontal marquee text, spanning the whole chain of [`MatrixBuffer`] panels.
+
+use super::font;
+use super::{MatrixBuffer, Transport};
+
+/// Maximum number of columns a scrolled message can occupy, including inter-character spacing.
+/// Bounds the virtual bitmap to a fixed-size array since the driver is `no_std`/no-alloc.
+pub const MAX_TEXT_COLUMNS: usize = 256;
+
+/// Renders ASCII text into a virtual column bitmap wider than the physical panels and steps a
+/// window of it across the chain one column at a time, for a continuous scrolling message.
+pub struct Scroller<T, const N: usize> {
+    buffer: MatrixBuffer<T, N>,
+    columns: [u8; MAX_TEXT_COLUMNS],
+    len: usize,
+    offset: usize,
+}
+
+impl<T: Transport, const N: usize> Scroller<T, N> {
+    pub fn new(buffer: MatrixBuffer<T, N>) -> Self {
+        Scroller {
+            buffer,
+            columns: [0; MAX_TEXT_COLUMNS],
+            len: 0,
+            offset: 0,
+        }
+    }
+
+    /// Renders `text` into the virtual scroll bitmap, looking each character up in the bundled
+    /// 5x7 font and leaving one blank column of letter-spacing after it. Characters missing from
+    /// the font render as a blank cell. Truncates silently at [`MAX_TEXT_COLUMNS`] columns.
+    pub fn set_text(&mut self, text: &str) {
+        self.len = 0;
+        self.offset = 0;
+
+        'chars: for ch in text.chars() {
+            for column in font::glyph(ch) {
+                if self.len == MAX_TEXT_COLUMNS {
+                    break 'chars;
+                }
+                self.columns[self.len] = column;
+                self.len += 1;
+            }
+
+            if self.len == MAX_TEXT_COLUMNS {
+                break;
+            }
+            self.columns[self.len] = 0x00;
+            self.len += 1;
+        }
+    }
+
+    /// Advances the scroll window by one column and redraws the panels. Wraps back to the start
+    /// of the message once it has scrolled fully past.
+    pub fn scroll_step(&mut self) {
+        if self.len == 0 {
+            return;
+        }
+
+        // The message is followed by one blank panel-width, so it scrolls fully off the chain
+        // before coming round again.
+        let period = self.len + N * 8;
+        for x in 0..N * 8 {
+            let index = (self.offset + x) % period;
+            let column = if index < self.len { self.columns[index] } else { 0x00 };
+            for y in 0..8 {
+                self.buffer.set_pixel(x, y, (column >> y) & 0x1 != 0);
+            }
+        }
+        self.buffer.flush();
+
+        self.offset = (self.offset + 1) % period;
+    }
+
+    /// Loads `msg` and runs one full marquee pass across the chain, waiting `delay_ms`
+    /// milliseconds between each column step.
+    pub fn scroll_text(&mut self, msg: &str, delay_ms: u16) {
+        self.set_text(msg);
+
+        // One extra panel-width of steps so the message fully exits the display before wrapping.
+        let steps = self.len + N * 8;
+        for _ in 0..steps {
+            self.scroll_step();
+            arduino_hal::delay_ms(delay_ms as u32);
+        }
+    }
+}
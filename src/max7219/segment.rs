@@ -0,0 +1,154 @@
+//! Numeric 7-segment support, driven through the MAX7219's on-chip Code-B BCD decoder instead of
+//! the raw matrix segments used by [`super::MatrixBuffer`].
+
+use super::{Max7219, Transport, ADDRESS, DIGIT_ADDRESSES};
+
+/// Decimal point bit (bit 7) shared by both decode modes.
+const DECIMAL_POINT: u8 = 0x80;
+
+/// MAX7219 decode mode, set per device and shared by all 8 of its digits.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum DisplayMode {
+    /// Raw matrix segments, as driven by [`super::MatrixBuffer`].
+    NoDecode,
+    /// On-chip BCD decoder, for 7-segment numeric digits.
+    CodeB,
+}
+
+impl DisplayMode {
+    /// MODE_DECODE register mask: the MAX7219 only has one decode bit per digit, so selecting
+    /// `CodeB` enables it for all 8 digits on the device at once.
+    fn mask(self) -> u8 {
+        match self {
+            DisplayMode::NoDecode => 0x00,
+            DisplayMode::CodeB => 0xFF,
+        }
+    }
+}
+
+/// A single Code-B character, per the MAX7219 datasheet's font table.
+#[derive(Clone, Copy)]
+pub enum Digit {
+    Value(u8),
+    Blank,
+    Minus,
+    H,
+    E,
+    L,
+    P,
+}
+
+impl Digit {
+    fn code(self) -> u8 {
+        match self {
+            Digit::Value(v) => v.min(9),
+            Digit::Minus => 0xA,
+            Digit::E => 0xB,
+            Digit::H => 0xC,
+            Digit::L => 0xD,
+            Digit::P => 0xE,
+            Digit::Blank => 0xF,
+        }
+    }
+}
+
+/// 8-digit-per-device 7-segment display sitting on top of a [`Max7219`] chain of `N` devices.
+///
+/// Digit positions are addressed in one flat `0..N * 8` space spanning the whole chain, device 0
+/// holding positions `0..8`, device 1 positions `8..16`, and so on.
+pub struct SegmentDisplay<T, const N: usize> {
+    driver: Max7219<T, N>,
+}
+
+impl<T: Transport, const N: usize> SegmentDisplay<T, N> {
+    pub fn new(driver: Max7219<T, N>) -> Self {
+        SegmentDisplay { driver }
+    }
+
+    /// Runs the MAX7219 power-up sequence on every cascaded device in `mode`: the full 8-digit
+    /// scan limit, `intensity` (0..=15), display test off, and shutdown cleared to enable output.
+    /// Every digit starts blank.
+    pub fn init(&mut self, intensity: u8, mode: DisplayMode) {
+        for device in 0..N {
+            self.driver
+                .write_register_chained(device, ADDRESS::MODE_DECODE, mode.mask());
+            self.driver
+                .write_register_chained(device, ADDRESS::SCAN_LIMIT, 7);
+        }
+        self.driver.set_intensity(intensity);
+        self.driver.test(false);
+        self.driver.shutdown(false);
+
+        // Code-B's blank code lights segments in no-decode mode, where blank is all segments off.
+        let blank = match mode {
+            DisplayMode::CodeB => Digit::Blank.code(),
+            DisplayMode::NoDecode => 0x00,
+        };
+        for pos in 0..N * 8 {
+            self.driver
+                .write_register_chained(pos / 8, DIGIT_ADDRESSES[pos % 8], blank);
+        }
+    }
+
+    /// Sets every cascaded device's brightness, clamped to the chip's `0..=15` range. See
+    /// [`Max7219::set_intensity`].
+    pub fn set_intensity(&mut self, level: u8) {
+        self.driver.set_intensity(level);
+    }
+
+    /// Enters or exits low-power shutdown mode on every cascaded device. The digit contents are
+    /// unaffected. See [`Max7219::shutdown`].
+    pub fn shutdown(&mut self, enabled: bool) {
+        self.driver.shutdown(enabled);
+    }
+
+    /// Enables or disables display-test mode on every cascaded device. See [`Max7219::test`].
+    pub fn test(&mut self, enabled: bool) {
+        self.driver.test(enabled);
+    }
+
+    /// Steps every cascaded device's brightness one level towards `target`. See
+    /// [`Max7219::fade_to`].
+    pub fn fade_to(&mut self, target: u8, step_delay_ms: u16) -> bool {
+        self.driver.fade_to(target, step_delay_ms)
+    }
+
+    /// Writes `value` to the digit at flat position `pos` (`0..N * 8`), with the decimal point
+    /// lit if `dp` is set.
+    pub fn set_digit(&mut self, pos: usize, value: Digit, dp: bool) {
+        debug_assert!(pos < N * 8, "digit position out of bounds");
+
+        let device = pos / 8;
+        let digit = pos % 8;
+        let data = value.code() | if dp { DECIMAL_POINT } else { 0 };
+
+        self.driver
+            .write_register_chained(device, DIGIT_ADDRESSES[digit], data);
+    }
+
+    /// Spreads `value` across the digit positions, rightmost digit first, blanking every leading
+    /// position that isn't needed (and the sign, for negative values, if it fits).
+    pub fn display_number(&mut self, value: i32) {
+        let total = N * 8;
+        let negative = value < 0;
+        let mut magnitude = value.unsigned_abs();
+
+        for pos in 0..total {
+            self.set_digit(pos, Digit::Blank, false);
+        }
+
+        let mut pos = total;
+        while pos > 0 {
+            pos -= 1;
+            self.set_digit(pos, Digit::Value((magnitude % 10) as u8), false);
+            magnitude /= 10;
+
+            if magnitude == 0 {
+                if negative && pos > 0 {
+                    self.set_digit(pos - 1, Digit::Minus, false);
+                }
+                break;
+            }
+        }
+    }
+}